@@ -13,6 +13,16 @@ extern "C" {
     #[wasm_bindgen(method, setter)]
     fn set_enabled(this: &JsonDecoratorOptions, val: Boolean);
 
+    #[wasm_bindgen(method, getter)]
+    pub fn value(this: &JsonDecoratorOptions) -> Option<String>;
+
+    #[wasm_bindgen(method, setter)]
+    fn set_value(this: &JsonDecoratorOptions, val: &str);
+
+    pub type JsonNamingOptions;
+    #[wasm_bindgen(method, getter)]
+    pub fn strategy(this: &JsonNamingOptions) -> Option<String>;
+
     pub type InternalDecorators;
 
     pub type ClassType;
@@ -27,6 +37,18 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn decoratorsEnabled(this: &JsonStringifierParserCommonContext) -> Option<Object>;
 
+    // `{ [contextGroup]: { [classOrProperty]: string[] } }` — child groups that
+    // become active only while resolving the named class or property.
+    #[wasm_bindgen(method, getter)]
+    pub fn scopedContextGroups(this: &JsonStringifierParserCommonContext) -> Option<Object>;
+
+    // `Map<group, parentGroup>` — each group's fallback parent in the
+    // inheritance chain, ultimately rooting at `DEFAULT`. Callers MUST run it
+    // through `validate_context_group_inheritance` before setting it here so a
+    // cyclic graph is rejected eagerly rather than silently accepted.
+    #[wasm_bindgen(method, getter)]
+    pub fn parentOf(this: &JsonStringifierParserCommonContext) -> Option<js_sys::Map>;
+
     // Another type
 
     pub type MakeMetadataKeysWithContextOptions;
@@ -99,6 +121,140 @@ impl MakeMetadataKeyWithContextOptions {
     }
 }
 
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PropertyNamingStrategy {
+    CamelCase,
+    PascalCase,
+    SnakeCase,
+    KebabCase,
+    ScreamingSnakeCase,
+    LowerDotCase,
+    Identity,
+}
+
+impl PropertyNamingStrategy {
+    /// Resolve a strategy from the string carried by a `JsonNaming` decorator,
+    /// accepting both the Rust variant names and the Jackson-style aliases
+    /// (`SNAKE_CASE`, `LOWER_CAMEL_CASE`, ...). Unknown values fall back to
+    /// `Identity` so an unexpected strategy never mangles property names.
+    fn from_strategy_str(value: &str) -> PropertyNamingStrategy {
+        match value.to_uppercase().replace('-', "_").as_str() {
+            "CAMELCASE" | "LOWER_CAMEL_CASE" => PropertyNamingStrategy::CamelCase,
+            "PASCALCASE" | "UPPER_CAMEL_CASE" => PropertyNamingStrategy::PascalCase,
+            "SNAKECASE" | "SNAKE_CASE" => PropertyNamingStrategy::SnakeCase,
+            "KEBABCASE" | "KEBAB_CASE" => PropertyNamingStrategy::KebabCase,
+            "SCREAMINGSNAKECASE" | "SCREAMING_SNAKE_CASE" => {
+                PropertyNamingStrategy::ScreamingSnakeCase
+            }
+            "LOWERDOTCASE" | "LOWER_DOT_CASE" => PropertyNamingStrategy::LowerDotCase,
+            _ => PropertyNamingStrategy::Identity,
+        }
+    }
+}
+
+/// Split an identifier into its component words. Existing `_`, `-` and `.`
+/// separators are consumed, and a new word is started on every lower/digit ->
+/// upper boundary. Runs of consecutive capitals (acronyms such as `ID` in
+/// `fooBarID`) are kept together as a single token, and the final capital of
+/// an acronym is handed to the following lowercase word (`JSONData` ->
+/// [`JSON`, `Data`]). Empty words produced by leading or doubled separators
+/// are never emitted.
+fn tokenize(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c == '.' {
+            if !current.is_empty() {
+                words.push(current.clone());
+                current.clear();
+            }
+            continue;
+        }
+
+        if !current.is_empty() {
+            let prev = chars[i - 1];
+            if c.is_uppercase() && (prev.is_lowercase() || prev.is_ascii_digit()) {
+                words.push(current.clone());
+                current.clear();
+            } else if c.is_lowercase() && prev.is_uppercase() && current.len() >= 2 {
+                let last = current.pop().unwrap();
+                words.push(current.clone());
+                current.clear();
+                current.push(last);
+            }
+        }
+
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+        None => String::new(),
+    }
+}
+
+/// Transform a property name according to `strategy`, following the tokenizing
+/// rules of [`tokenize`].
+#[wasm_bindgen]
+pub fn apply_naming_strategy(name: &str, strategy: PropertyNamingStrategy) -> String {
+    if strategy == PropertyNamingStrategy::Identity {
+        return name.to_string();
+    }
+
+    let words = tokenize(name);
+    if words.is_empty() {
+        return String::new();
+    }
+
+    match strategy {
+        PropertyNamingStrategy::CamelCase => words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                if i == 0 {
+                    w.to_lowercase()
+                } else {
+                    capitalize(w)
+                }
+            })
+            .collect(),
+        PropertyNamingStrategy::PascalCase => words.iter().map(|w| capitalize(w)).collect(),
+        PropertyNamingStrategy::SnakeCase => words
+            .iter()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<String>>()
+            .join("_"),
+        PropertyNamingStrategy::KebabCase => words
+            .iter()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<String>>()
+            .join("-"),
+        PropertyNamingStrategy::ScreamingSnakeCase => words
+            .iter()
+            .map(|w| w.to_uppercase())
+            .collect::<Vec<String>>()
+            .join("_"),
+        PropertyNamingStrategy::LowerDotCase => words
+            .iter()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<String>>()
+            .join("."),
+        PropertyNamingStrategy::Identity => name.to_string(),
+    }
+}
+
 #[allow(non_snake_case)]
 mod Reflect {
     use wasm_bindgen::prelude::*;
@@ -121,12 +277,132 @@ mod Reflect {
     }
 }
 
+thread_local! {
+    // Memoizes resolved `(target, metadata-key-with-context)` lookups so repeated
+    // prototype-chain walks over the same object graph are paid for only once.
+    // Keyed by object identity (outer map) then by a string combining the
+    // context token, the property key and the fully-qualified
+    // metadata-key-with-context (inner map); negative results are stored
+    // as `JsValue::NULL` so a miss is not re-walked on every call.
+    static METADATA_CACHE: js_sys::Map = js_sys::Map::new();
+
+    // Stable identity tokens for contexts, so two contexts that share a group
+    // name but differ in `_internalDecorators`/`decoratorsEnabled` don't collide
+    // in the cache. Keyed by context object identity -> monotonic number.
+    static CONTEXT_TOKENS: js_sys::Map = js_sys::Map::new();
+    static CONTEXT_TOKEN_COUNTER: std::cell::Cell<f64> = const { std::cell::Cell::new(0.0) };
+}
+
+fn context_token(context: Option<&JsonStringifierParserCommonContext>) -> f64 {
+    let context = match context {
+        Some(context) => context,
+        None => return 0.0,
+    };
+    CONTEXT_TOKENS.with(|tokens| {
+        let existing = tokens.get(context);
+        if let Some(existing) = existing.as_f64() {
+            return existing;
+        }
+        let next = CONTEXT_TOKEN_COUNTER.with(|counter| {
+            let next = counter.get() + 1.0;
+            counter.set(next);
+            next
+        });
+        tokens.set(context, &JsValue::from_f64(next));
+        next
+    })
+}
+
+// The resolved value depends on `(target, property_key, metadata-key, context)`;
+// the same prototype is reused for every property on a class, so `property_key`
+// and a context-identity token must both be part of the inner key.
+fn metadata_cache_key(
+    property_key: Option<&String>,
+    metadata_key_with_context: &str,
+    token: f64,
+) -> String {
+    format!(
+        "{}|{}|{}",
+        token,
+        property_key.map(|k| k.as_str()).unwrap_or(""),
+        metadata_key_with_context
+    )
+}
+
+fn metadata_cache_get(
+    target: &Object,
+    property_key: Option<&String>,
+    metadata_key_with_context: &str,
+    token: f64,
+) -> Option<Option<JsonDecoratorOptions>> {
+    METADATA_CACHE.with(|cache| {
+        let inner = cache.get(target);
+        if inner.is_undefined() {
+            return None;
+        }
+        let inner: js_sys::Map = inner.into();
+        let key = JsValue::from_str(&metadata_cache_key(
+            property_key,
+            metadata_key_with_context,
+            token,
+        ));
+        if !inner.has(&key) {
+            return None;
+        }
+        let value = inner.get(&key);
+        if value.is_null() {
+            Some(None)
+        } else {
+            Some(Some(value.into()))
+        }
+    })
+}
+
+fn metadata_cache_set(
+    target: &Object,
+    property_key: Option<&String>,
+    metadata_key_with_context: &str,
+    token: f64,
+    value: &Option<JsonDecoratorOptions>,
+) {
+    METADATA_CACHE.with(|cache| {
+        let inner = cache.get(target);
+        let inner: js_sys::Map = if inner.is_undefined() {
+            let map = js_sys::Map::new();
+            cache.set(target, &map);
+            map
+        } else {
+            inner.into()
+        };
+        let stored = match value {
+            Some(options) => JsValue::from(options.clone()),
+            None => JsValue::NULL,
+        };
+        let key = metadata_cache_key(property_key, metadata_key_with_context, token);
+        inner.set(&JsValue::from_str(&key), &stored);
+    });
+}
+
+/// Invalidate the metadata resolution cache. Callers must invoke this after
+/// mutating decorators at runtime so stale lookups are not served.
+#[wasm_bindgen]
+pub fn clear_metadata_cache() {
+    METADATA_CACHE.with(|cache| cache.clear());
+}
+
 pub fn find_metadata_by_metadata_key_with_context(
     metadata_key_with_context: &str,
     target: &Object,
     property_key: Option<&String>,
     context: Option<&JsonStringifierParserCommonContext>,
 ) -> Option<JsonDecoratorOptions> {
+    let token = context_token(context);
+    if let Some(cached) =
+        metadata_cache_get(target, property_key, metadata_key_with_context, token)
+    {
+        return cached;
+    }
+
     let mut json_decorator_options: Option<JsonDecoratorOptions>;
     let has_property_key = property_key.is_some();
 
@@ -186,6 +462,13 @@ pub fn find_metadata_by_metadata_key_with_context(
         parent = prototype.unwrap();
     }
 
+    metadata_cache_set(
+        target,
+        property_key,
+        metadata_key_with_context,
+        token,
+        &json_decorator_options,
+    );
     json_decorator_options
 }
 
@@ -259,6 +542,204 @@ pub fn make_metadata_keys_with_context(
     }
 }
 
+/// Collect the names a scoped-context entry may be keyed by for the current
+/// lookup: the property name when resolving a property, otherwise (and in
+/// addition) the class name carried on `target`.
+fn scope_names(target: &Object, property_key: Option<&String>) -> Vec<String> {
+    let mut names = Vec::new();
+    if let Some(property_key) = property_key {
+        names.push(property_key.clone());
+    }
+    // jackson-js passes the class *prototype* as `target` for property metadata,
+    // and `prototype.name` is `undefined` — the class name lives on
+    // `prototype.constructor.name`. Both the constructor's name and a `name`
+    // directly on the target (the constructor function itself) are collected
+    // when present.
+    if let Ok(constructor) = js_sys::Reflect::get(target, &JsValue::from_str("constructor")) {
+        if let Ok(name) = js_sys::Reflect::get(&constructor, &JsValue::from_str("name")) {
+            if let Some(name) = name.as_string() {
+                names.push(name);
+            }
+        }
+    }
+    if let Ok(name) = js_sys::Reflect::get(target, &JsValue::from_str("name")) {
+        if let Some(name) = name.as_string() {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Append the groups scoped in by each requested group, preserving order and
+/// skipping duplicates. Explicitly requested groups keep top priority; the
+/// groups they scope in follow. `scoped_for` yields the scoped groups a given
+/// requested group activates for the current class/property.
+fn append_scoped_groups(
+    requested: &[String],
+    mut scoped_for: impl FnMut(&str) -> Vec<String>,
+) -> Vec<String> {
+    let mut groups = requested.to_vec();
+    for context_group in requested {
+        for scoped in scoped_for(context_group) {
+            if !groups.contains(&scoped) {
+                groups.push(scoped);
+            }
+        }
+    }
+    groups
+}
+
+/// Resolve the child groups a `context_group` scopes to one of `scope_names`,
+/// reading the `{ [contextGroup]: { [classOrProperty]: string[] } }` map off
+/// the context. Returns an empty vector when no entry matches.
+fn scoped_groups_for(
+    context: &JsonStringifierParserCommonContext,
+    context_group: &str,
+    scope_names: &[String],
+) -> Vec<String> {
+    let mut result = Vec::new();
+    let scoped = match context.scopedContextGroups() {
+        Some(scoped) => scoped,
+        None => return result,
+    };
+
+    let by_scope = match js_sys::Reflect::get(&scoped, &JsValue::from_str(context_group)) {
+        Ok(by_scope) if by_scope.is_object() => by_scope,
+        _ => return result,
+    };
+
+    for scope_name in scope_names {
+        if let Ok(groups) = js_sys::Reflect::get(&by_scope, &JsValue::from_str(scope_name)) {
+            if let Ok(groups) = groups.dyn_into::<Array>() {
+                for group in groups.iter() {
+                    if let Some(group) = group.as_string() {
+                        result.push(group);
+                    }
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Validate a context-group inheritance graph, rejecting cycles. This only
+/// checks the graph and does not set it on the context; callers run the graph
+/// through this before setting it so a malformed chain is surfaced eagerly,
+/// naming the group at which a cycle closes, instead of at resolution time.
+#[wasm_bindgen]
+pub fn validate_context_group_inheritance(parent_of: &js_sys::Map) -> Result<(), JsValue> {
+    let edges = inheritance_edges(parent_of);
+    match detect_inheritance_cycle(&edges) {
+        Some(group) => Err(JsValue::from_str(&format!(
+            "Cyclic context group inheritance detected at group \"{}\".",
+            group
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Read a `Map<group, parentGroup>` into a plain `(group, parent)` edge list.
+fn inheritance_edges(parent_of: &js_sys::Map) -> Vec<(String, String)> {
+    let mut edges = Vec::new();
+    let entries = parent_of.entries();
+    while let Ok(next) = entries.next() {
+        if next.done() {
+            break;
+        }
+        if let Ok(pair) = next.value().dyn_into::<Array>() {
+            if let (Some(group), Some(parent)) = (pair.get(0).as_string(), pair.get(1).as_string())
+            {
+                edges.push((group, parent));
+            }
+        }
+    }
+    edges
+}
+
+/// Find a group at which the `(group, parent)` edges close a cycle, if any.
+fn detect_inheritance_cycle(edges: &[(String, String)]) -> Option<String> {
+    for (start, _) in edges {
+        let mut current = start.clone();
+        let mut seen: Vec<String> = vec![current.clone()];
+        loop {
+            let parent = edges.iter().find(|(g, _)| g == &current).map(|(_, p)| p);
+            match parent {
+                Some(parent) => {
+                    if seen.contains(parent) {
+                        return Some(parent.clone());
+                    }
+                    seen.push(parent.clone());
+                    current = parent.clone();
+                }
+                None => break,
+            }
+        }
+    }
+    None
+}
+
+/// Expand `base_groups` along `parent_of` breadth-first: every base group is
+/// emitted first, preserving its priority, and only then are inherited
+/// ancestors appended. `default_group` is never emitted early and is forced
+/// strictly last, so an explicitly-requested (or scoped) group is always probed
+/// before `DEFAULT`, even when another requested group inherits `DEFAULT`
+/// directly. Groups already visited are skipped, so a diamond-shaped graph
+/// never probes the same key twice; dedup also makes this terminate on a cyclic
+/// graph, but callers should still reject cycles up front via
+/// [`validate_context_group_inheritance`].
+fn expand_inheritance_chain(
+    base_groups: &[String],
+    parent_of: impl Fn(&str) -> Option<String>,
+    default_group: &str,
+) -> Vec<String> {
+    let mut ordered: Vec<String> = Vec::new();
+
+    // Base groups first, in their given priority; `DEFAULT` is held back.
+    for group in base_groups {
+        if group != default_group && !ordered.contains(group) {
+            ordered.push(group.clone());
+        }
+    }
+
+    // Append inherited ancestors breadth-first: processing `ordered` in insertion
+    // order means a group's parents are only reached after all higher-priority
+    // groups have been emitted. `DEFAULT` is still held back.
+    let mut i = 0;
+    while i < ordered.len() {
+        let current = ordered[i].clone();
+        if let Some(parent) = parent_of(&current) {
+            if parent != default_group && !ordered.contains(&parent) {
+                ordered.push(parent);
+            }
+        }
+        i += 1;
+    }
+
+    ordered.push(default_group.to_string());
+    ordered
+}
+
+/// Expand `base_groups` along the inheritance chain read off `context`, with
+/// `DEFAULT` as the final fallback. The graph must already have been validated
+/// via [`validate_context_group_inheritance`]; dedup keeps this terminating
+/// even if it was not.
+fn expand_inheritance(
+    context: &JsonStringifierParserCommonContext,
+    base_groups: &[String],
+) -> Vec<String> {
+    let parent_of = context.parentOf();
+    expand_inheritance_chain(
+        base_groups,
+        |group| {
+            parent_of
+                .as_ref()
+                .and_then(|parent_of| parent_of.get(&JsValue::from_str(group)).as_string())
+        },
+        default_context_group::DEFAULT_CONTEXT_GROUP,
+    )
+}
+
 pub fn find_metadata(
     metadata_key: &str,
     target: &Object,
@@ -267,9 +748,16 @@ pub fn find_metadata(
 ) -> Option<JsonDecoratorOptions> {
 
     let context_groups_with_default = {
-        let mut groups = context.withContextGroups().clone().unwrap_or(Vec::new());
-        groups.push(default_context_group::DEFAULT_CONTEXT_GROUP.to_string());
-        groups
+        let requested = context.withContextGroups().clone().unwrap_or(Vec::new());
+        let scope_names = scope_names(target, property_key);
+
+        // Explicitly requested groups keep top priority; the groups they scope
+        // in are probed next. Inherited ancestors and `DEFAULT` are appended by
+        // `expand_inheritance`, which keeps `DEFAULT` strictly last.
+        let base = append_scoped_groups(&requested, |context_group| {
+            scoped_groups_for(context, context_group, &scope_names)
+        });
+        expand_inheritance(context, &base)
     };
 
     for context_group in context_groups_with_default {
@@ -293,6 +781,14 @@ pub fn find_metadata(
     return None;
 }
 
+/// Whether `metadata_key` denotes the `JsonProperty` decorator exactly, matching
+/// the bare key or any `:`-separated `JsonProperty` segment of a
+/// metadata-key-with-context, so sibling decorators such as `JsonPropertyOrder`
+/// and `JsonPropertyDescription` are never mistaken for it.
+fn is_json_property_key(metadata_key: &str) -> bool {
+    metadata_key == "JsonProperty" || metadata_key.split(':').any(|segment| segment == "JsonProperty")
+}
+
 #[wasm_bindgen]
 pub fn get_metadata(
     metadata_key: String,
@@ -311,11 +807,39 @@ pub fn get_metadata(
         find_metadata(&metadata_key, &target, property_key.as_ref(), &context)
     };
 
-    if let Some(decorator_options) = json_decorator_options {
+    if let Some(mut decorator_options) = json_decorator_options {
         if JsValue::is_undefined(&decorator_options) {
             return JsValue::undefined();
         }
 
+        // `@JsonNaming` renames property keys only, so the strategy is applied
+        // exclusively to the `JsonProperty` decorator's `value` — never to the
+        // `value` carried by other decorators (`JsonRootName`, `JsonTypeName`,
+        // ...) nor to siblings such as `JsonPropertyOrder`. Gating here also
+        // keeps the extra `find_metadata` walk off the hot path for every other
+        // decorator.
+        if is_json_property_key(&metadata_key) {
+            if let Some(naming) = find_metadata("JsonNaming", &target, None, &context) {
+                let naming_options = JsonNamingOptions::from(JsValue::from(naming));
+                if let Some(strategy) = naming_options.strategy() {
+                    if let Some(name) = decorator_options.value() {
+                        // Copy the options: the resolved object is the live
+                        // (and now cached) metadata shared across reads, so
+                        // mutating it would permanently rewrite the user's
+                        // decorator and double-apply on the next pass.
+                        let copy: JsonDecoratorOptions =
+                            Object::assign(&Object::new(), decorator_options.as_ref())
+                                .unchecked_into();
+                        copy.set_value(&apply_naming_strategy(
+                            &name,
+                            PropertyNamingStrategy::from_strategy_str(&strategy),
+                        ));
+                        decorator_options = copy;
+                    }
+                }
+            }
+        }
+
         if let Some(decorators_enabled) = &context.decoratorsEnabled() {
             let decorator_keys: Array = Object::keys(&decorators_enabled);
             let decorator_key = decorator_keys.iter().find(|key| {
@@ -347,3 +871,99 @@ pub fn get_metadata(
     }
     JsValue::undefined()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn scoped_group_activates_for_matching_class() {
+        // A `public` group scopes in `audit` only for the `Account` class.
+        let requested = s(&["public"]);
+        let scoped_for = |group: &str| {
+            if group == "public" {
+                // Simulates `scopedContextGroups["public"]["Account"]` matching
+                // one of the current scope names.
+                s(&["audit"])
+            } else {
+                Vec::new()
+            }
+        };
+
+        let activated = append_scoped_groups(&requested, scoped_for);
+        assert_eq!(activated, s(&["public", "audit"]));
+
+        // A class whose name does not match pulls in no scoped group.
+        let none = append_scoped_groups(&requested, |_| Vec::new());
+        assert_eq!(none, s(&["public"]));
+    }
+
+    #[test]
+    fn scoped_group_outranks_parents_but_not_requested() {
+        // `public` scopes in `audit`, and `public` inherits `base` -> `DEFAULT`.
+        let base = append_scoped_groups(&s(&["public"]), |group| {
+            if group == "public" {
+                s(&["audit"])
+            } else {
+                Vec::new()
+            }
+        });
+        let edges = vec![
+            ("public".to_string(), "base".to_string()),
+            ("base".to_string(), "DEFAULT".to_string()),
+        ];
+        let parent_of = |group: &str| {
+            edges
+                .iter()
+                .find(|(g, _)| g == group)
+                .map(|(_, p)| p.clone())
+        };
+
+        let ordered = expand_inheritance_chain(&base, parent_of, "DEFAULT");
+        // requested `public`, then scoped `audit`, then the inherited `base`,
+        // then `DEFAULT` last.
+        assert_eq!(ordered, s(&["public", "audit", "base", "DEFAULT"]));
+    }
+
+    #[test]
+    fn inheritance_chain_walks_to_default_with_diamond_dedup() {
+        // internal -> public -> DEFAULT, audit -> public -> DEFAULT.
+        let edges = vec![
+            ("internal".to_string(), "public".to_string()),
+            ("audit".to_string(), "public".to_string()),
+            ("public".to_string(), "DEFAULT".to_string()),
+        ];
+        let parent_of = |group: &str| {
+            edges
+                .iter()
+                .find(|(g, _)| g == group)
+                .map(|(_, p)| p.clone())
+        };
+
+        let ordered = expand_inheritance_chain(&s(&["internal", "audit"]), parent_of, "DEFAULT");
+        // Breadth-first: both requested groups precede inherited ancestors, and
+        // the requested `audit` group outranks `DEFAULT` despite `internal`
+        // inheriting `DEFAULT` through `public`. `public`/`DEFAULT` appear once
+        // despite the diamond.
+        assert_eq!(ordered, s(&["internal", "audit", "public", "DEFAULT"]));
+    }
+
+    #[test]
+    fn cycle_detection_names_offending_group() {
+        let acyclic = vec![
+            ("internal".to_string(), "public".to_string()),
+            ("public".to_string(), "DEFAULT".to_string()),
+        ];
+        assert_eq!(detect_inheritance_cycle(&acyclic), None);
+
+        let cyclic = vec![
+            ("a".to_string(), "b".to_string()),
+            ("b".to_string(), "a".to_string()),
+        ];
+        assert_eq!(detect_inheritance_cycle(&cyclic), Some("a".to_string()));
+    }
+}